@@ -2,6 +2,8 @@ pub mod error;
 pub mod fns;
 mod point;
 mod poline;
+mod transform;
 mod types;
 pub use point::{ColorPoint, Hsl};
-pub use poline::Poline;
+pub use poline::{HarmonyAxis, PathMode, Poline};
+pub use transform::Transform;
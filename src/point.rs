@@ -1,6 +1,8 @@
+use crate::transform::Transform;
 use crate::types::Transformer;
 use std::convert::From;
 use std::f64::consts::PI;
+use std::ops::{Add, Mul, Sub};
 
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Point2 {
@@ -34,6 +36,77 @@ impl Point3 {
     }
 }
 
+impl Point3 {
+    pub(crate) fn new(x: f64, y: f64, z: f64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub(crate) fn components(&self) -> (f64, f64, f64) {
+        (self.x, self.y, self.z)
+    }
+
+    pub(crate) fn apply_transform(&self, t: &Transform) -> Self {
+        let (x, y, z) = t.apply_point(self.x, self.y, self.z);
+        Point3 { x, y, z }
+    }
+
+    pub fn dot(&self, other: &Point3) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn length(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Point3 {
+        *self * (1f64 / self.length())
+    }
+
+    pub fn project_on(&self, line_a: &Point3, line_b: &Point3) -> Point3 {
+        let line = *line_b - *line_a;
+        let to_self = *self - *line_a;
+        let t = to_self.dot(&line) / line.dot(&line);
+
+        *line_a + line * t
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, rhs: Point3) -> Point3 {
+        Point3 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, rhs: Point3) -> Point3 {
+        Point3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl Mul<f64> for Point3 {
+    type Output = Point3;
+
+    fn mul(self, rhs: f64) -> Point3 {
+        Point3 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
 impl From<(Hsl, bool)> for Point3 {
     fn from((hsl, inverted): (Hsl, bool)) -> Self {
         let Hsl { h, s, l } = hsl;
@@ -85,6 +158,30 @@ impl From<(Point3, bool)> for Hsl {
     }
 }
 
+impl Hsl {
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        let Hsl { h, s, l } = *self;
+
+        let c = (1f64 - (2f64 * l - 1f64).abs()) * s;
+        let h_prime = h / 60f64;
+        let x = c * (1f64 - (h_prime % 2f64 - 1f64).abs());
+
+        let (r1, g1, b1) = match h_prime as i32 {
+            0 => (c, x, 0f64),
+            1 => (x, c, 0f64),
+            2 => (0f64, c, x),
+            3 => (0f64, x, c),
+            4 => (x, 0f64, c),
+            _ => (c, 0f64, x),
+        };
+
+        let m = l - c / 2f64;
+        let to_channel = |v: f64| ((v + m) * 255f64).round().clamp(0f64, 255f64) as u8;
+
+        (to_channel(r1), to_channel(g1), to_channel(b1))
+    }
+}
+
 pub struct HslPairInit {
     start_hue: f64,
     saturation: Point2,
@@ -164,6 +261,21 @@ impl Hsl {
     }
 }
 
+// packs the x/y/z lerps into one pass instead of three separate scalar lerps
+fn lerp3(t: [f64; 3], p1: &Point3, p2: &Point3) -> Point3 {
+    let a = [p1.x, p1.y, p1.z];
+    let b = [p2.x, p2.y, p2.z];
+    let mut out = [0f64; 3];
+    for i in 0..3 {
+        out[i] = (1f64 - t[i]) * a[i] + t[i] * b[i];
+    }
+    Point3 {
+        x: out[0],
+        y: out[1],
+        z: out[2],
+    }
+}
+
 fn vector_on_line(
     t: f64,
     p1: &Point3,
@@ -173,15 +285,9 @@ fn vector_on_line(
     fy: Transformer,
     fz: Transformer,
 ) -> Point3 {
-    let t_modified_x = fx(t, inverted);
-    let t_modified_y = fy(t, inverted);
-    let t_modified_z = fz(t, inverted);
+    let t_modified = [fx(t, inverted), fy(t, inverted), fz(t, inverted)];
 
-    let x = (1f64 - t_modified_x) * p1.x + t_modified_x * p2.x;
-    let y = (1f64 - t_modified_y) * p1.y + t_modified_y * p2.y;
-    let z = (1f64 - t_modified_z) * p1.z + t_modified_z * p2.z;
-
-    Point3 { x, y, z }
+    lerp3(t_modified, p1, p2)
 }
 
 pub fn vectors_on_line(
@@ -201,6 +307,50 @@ pub fn vectors_on_line(
         .collect()
 }
 
+fn catmull_rom_point(
+    t: f64,
+    neighbors: &[Point3; 4],
+    inverted: bool,
+    fx: Transformer,
+    fy: Transformer,
+    fz: Transformer,
+) -> Point3 {
+    let [p0, p1, p2, p3] = neighbors;
+
+    let t_modified_x = fx(t, inverted);
+    let t_modified_y = fy(t, inverted);
+    let t_modified_z = fz(t, inverted);
+
+    fn component(t: f64, a: f64, b: f64, c: f64, d: f64) -> f64 {
+        0.5 * (2.0 * b
+            + (-a + c) * t
+            + (2.0 * a - 5.0 * b + 4.0 * c - d) * t.powi(2)
+            + (-a + 3.0 * b - 3.0 * c + d) * t.powi(3))
+    }
+
+    Point3 {
+        x: component(t_modified_x, p0.x, p1.x, p2.x, p3.x),
+        y: component(t_modified_y, p0.y, p1.y, p2.y, p3.y),
+        z: component(t_modified_z, p0.z, p1.z, p2.z, p3.z),
+    }
+}
+
+pub fn vectors_on_catmull_rom(
+    neighbors: &[Point3; 4],
+    num_points: i32,
+    inverted: bool,
+    fx: Transformer,
+    fy: Transformer,
+    fz: Transformer,
+) -> Vec<Point3> {
+    (0..num_points)
+        .map(move |i| {
+            let t: f64 = i as f64 / (num_points - 1) as f64;
+            catmull_rom_point(t, neighbors, inverted, fx, fy, fz)
+        })
+        .collect()
+}
+
 pub struct PartialPoint3(Option<f64>, Option<f64>, Option<f64>);
 
 impl PartialPoint3 {
@@ -304,3 +454,122 @@ impl From<(Point3, bool)> for ColorPoint {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fns::PositionFn;
+
+    #[test]
+    fn catmull_rom_interpolates_through_anchors() {
+        let identity = PositionFn::Linear.get_fn();
+        let p0 = Point3::new(0.0, 0.0, 0.0);
+        let p1 = Point3::new(1.0, 0.0, 0.0);
+        let p2 = Point3::new(1.0, 1.0, 0.0);
+        let p3 = Point3::new(0.0, 1.0, 0.0);
+        let neighbors = [p0, p1, p2, p3];
+
+        let start = catmull_rom_point(0.0, &neighbors, false, identity, identity, identity);
+        let end = catmull_rom_point(1.0, &neighbors, false, identity, identity, identity);
+
+        assert_eq!((start.x, start.y, start.z), (p1.x, p1.y, p1.z));
+        assert_eq!((end.x, end.y, end.z), (p2.x, p2.y, p2.z));
+    }
+
+    #[test]
+    fn duplicate_endpoint_matches_open_segment_convention() {
+        // open-segment endpoints duplicate the neighbor (P0 = P1 or P3 = P2)
+        let identity = PositionFn::Linear.get_fn();
+        let p1 = Point3::new(0.0, 0.0, 0.0);
+        let p2 = Point3::new(2.0, 0.0, 0.0);
+        let neighbors = [p1, p1, p2, p2];
+
+        let mid = catmull_rom_point(0.5, &neighbors, false, identity, identity, identity);
+
+        assert!((mid.x - 1.0).abs() < 1e-9);
+        assert_eq!(mid.y, 0.0);
+    }
+
+    #[test]
+    fn vectors_on_line_linear_identity_is_straight_lerp() {
+        let identity = PositionFn::Linear.get_fn();
+        let p1 = Point3::new(0.0, 0.0, 0.0);
+        let p2 = Point3::new(4.0, 2.0, 1.0);
+
+        let points = vectors_on_line(&p1, &p2, 5, false, identity, identity, identity);
+
+        assert_eq!(points.len(), 5);
+        let mid = points[2];
+        assert!((mid.x - 2.0).abs() < 1e-9);
+        assert!((mid.y - 1.0).abs() < 1e-9);
+        assert!((mid.z - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn normalize_produces_unit_length() {
+        let v = Point3::new(3.0, 4.0, 0.0);
+        let n = v.normalize();
+        assert!((n.length() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn project_on_snaps_to_nearest_point_on_line() {
+        let a = Point3::new(0.0, 0.0, 0.0);
+        let b = Point3::new(2.0, 0.0, 0.0);
+        let p = Point3::new(1.0, 1.0, 0.0);
+
+        let projected = p.project_on(&a, &b);
+
+        assert!((projected.x - 1.0).abs() < 1e-9);
+        assert!((projected.y - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn to_rgb_matches_known_fixtures() {
+        assert_eq!(
+            Hsl {
+                h: 0.0,
+                s: 1.0,
+                l: 0.5
+            }
+            .to_rgb(),
+            (255, 0, 0)
+        );
+        assert_eq!(
+            Hsl {
+                h: 120.0,
+                s: 1.0,
+                l: 0.5
+            }
+            .to_rgb(),
+            (0, 255, 0)
+        );
+        assert_eq!(
+            Hsl {
+                h: 240.0,
+                s: 1.0,
+                l: 0.5
+            }
+            .to_rgb(),
+            (0, 0, 255)
+        );
+        assert_eq!(
+            Hsl {
+                h: 0.0,
+                s: 0.0,
+                l: 1.0
+            }
+            .to_rgb(),
+            (255, 255, 255)
+        );
+        assert_eq!(
+            Hsl {
+                h: 0.0,
+                s: 0.0,
+                l: 0.0
+            }
+            .to_rgb(),
+            (0, 0, 0)
+        );
+    }
+}
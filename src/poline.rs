@@ -1,12 +1,51 @@
-use std::{convert::From, iter::Peekable};
+use std::{convert::From, f64::consts::PI, iter::Peekable};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 use crate::{
     error::PolineError,
     fns::PositionFn,
-    point::{vectors_on_line, ColorPoint, Hsl, HslPairInit, PartialPoint3, PointOrHsl},
+    point::{
+        vectors_on_catmull_rom, vectors_on_line, ColorPoint, Hsl, HslPairInit, PartialPoint3,
+        Point3, PointOrHsl,
+    },
+    transform::Transform,
     types::Transformer,
 };
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PathMode {
+    #[default]
+    Linear,
+    CatmullRom,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HarmonyAxis {
+    Complementary,
+    Angle(f64),
+}
+
+fn reflect_point(point: Point3, axis: &HarmonyAxis) -> Point3 {
+    let center = Point3::new(0.5, 0.5, 0f64);
+    let v = point - center;
+
+    let n = match axis {
+        HarmonyAxis::Complementary => v.normalize(),
+        HarmonyAxis::Angle(degrees) => {
+            let radians = (degrees + 90f64) / (180f64 / PI);
+            Point3::new(radians.cos(), radians.sin(), 0f64)
+        }
+    };
+
+    let reflected = v - n * (2f64 * v.dot(&n));
+    let (rx, ry, _) = reflected.components();
+    let (_, _, pz) = point.components();
+
+    Point3::new(0.5 + rx, 0.5 + ry, pz)
+}
+
 pub struct Poline {
     anchor_points: Vec<ColorPoint>,
     num_points: i32,
@@ -17,6 +56,7 @@ pub struct Poline {
     points: Vec<Vec<ColorPoint>>,
     anchor_pairs: Vec<Vec<ColorPoint>>,
     closed_loop: bool,
+    path_mode: PathMode,
 }
 
 pub struct PolineBuilder {
@@ -27,6 +67,7 @@ pub struct PolineBuilder {
     position_fn_z: Option<fn(f64, bool) -> f64>,
     closed_loop: bool,
     inverted: bool,
+    path_mode: PathMode,
 }
 
 impl PolineBuilder {
@@ -65,6 +106,11 @@ impl PolineBuilder {
         self
     }
 
+    pub fn path_mode(mut self, path_mode: PathMode) -> Self {
+        self.path_mode = path_mode;
+        self
+    }
+
     pub fn build(self) -> Result<Poline, PolineError> {
         if self.anchor_colors.len() < 2 {
             return Err(PolineError::InvalidAnchorColorCount);
@@ -97,6 +143,7 @@ impl PolineBuilder {
             pos_fn_z,
             inverted: self.inverted,
             closed_loop: self.closed_loop,
+            path_mode: self.path_mode,
         };
         out.update_anchor_pairs();
         Ok(out)
@@ -115,6 +162,7 @@ impl Default for PolineBuilder {
             position_fn_z: None,
             closed_loop: false,
             inverted: false,
+            path_mode: PathMode::default(),
         }
     }
 }
@@ -167,30 +215,76 @@ impl Poline {
             anchor_pairs.push(pair);
         }
 
+        let anchor_pairs_len = anchor_pairs.len();
+
+        #[cfg(feature = "rayon")]
+        let points = anchor_pairs
+            .par_iter()
+            .enumerate()
+            .map(|(i, pair)| self.segment_points(i, anchor_pairs_len, pair))
+            .collect();
+
+        #[cfg(not(feature = "rayon"))]
         let points = anchor_pairs
             .iter()
             .enumerate()
-            .map(|(i, pair)| -> Vec<ColorPoint> {
-                let p1 = pair.first().map(|p| p.point).unwrap_or_default();
-                let p2 = pair.get(1).map(|p| p.point).unwrap_or_default();
+            .map(|(i, pair)| self.segment_points(i, anchor_pairs_len, pair))
+            .collect();
+
+        self.points = points;
+        self.anchor_pairs = anchor_pairs;
+    }
+
+    // shared by the serial and rayon (feature-gated) iteration paths in update_anchor_pairs
+    fn segment_points(&self, i: usize, anchor_pairs_len: usize, pair: &[ColorPoint]) -> Vec<ColorPoint> {
+        let p1 = pair.first().map(|p| p.point).unwrap_or_default();
+        let p2 = pair.get(1).map(|p| p.point).unwrap_or_default();
+
+        let raw_points = match self.path_mode {
+            PathMode::Linear => vectors_on_line(
+                &p1,
+                &p2,
+                self.num_points,
+                i.is_multiple_of(2),
+                self.pos_fn_x,
+                self.pos_fn_y,
+                self.pos_fn_z,
+            ),
+            PathMode::CatmullRom => {
+                let anchor_points = &self.anchor_points;
+                let len = anchor_points.len();
+
+                let p0 = if self.closed_loop {
+                    anchor_points[(i + len - 1) % len].point
+                } else if i == 0 {
+                    p1
+                } else {
+                    anchor_points[i - 1].point
+                };
 
-                vectors_on_line(
-                    &p1,
-                    &p2,
+                let p3 = if self.closed_loop {
+                    anchor_points[(i + 2) % len].point
+                } else if i == anchor_pairs_len - 1 {
+                    p2
+                } else {
+                    anchor_points[i + 2].point
+                };
+
+                vectors_on_catmull_rom(
+                    &[p0, p1, p2, p3],
                     self.num_points,
-                    (i % 2) == 0,
+                    i.is_multiple_of(2),
                     self.pos_fn_x,
                     self.pos_fn_y,
                     self.pos_fn_z,
                 )
-                .into_iter()
-                .map(|p| ColorPoint::from((p, self.inverted)))
-                .collect()
-            })
-            .collect();
+            }
+        };
 
-        self.points = points;
-        self.anchor_pairs = anchor_pairs;
+        raw_points
+            .into_iter()
+            .map(|p| ColorPoint::from((p, self.inverted)))
+            .collect()
     }
 
     pub fn num_points(&self) -> i32 {
@@ -274,6 +368,25 @@ impl Poline {
         Ok(out)
     }
 
+    pub fn snap_anchor_to_line(
+        &mut self,
+        index: usize,
+        a: Point3,
+        b: Point3,
+    ) -> Result<ColorPoint, PolineError> {
+        if index >= self.anchor_points.len() {
+            return Err(PolineError::PointIndexOutOfBounds);
+        }
+
+        let mut out = self.anchor_points.remove(index);
+        let snapped = out.point.project_on(&a, &b);
+        out.set_postion(snapped);
+
+        self.anchor_points.insert(index, out);
+
+        Ok(out)
+    }
+
     pub fn get_closest_anchor_point(&self, point_or_hsl: PointOrHsl) -> Option<(ColorPoint, f64)> {
         let distances: Vec<f64> = match point_or_hsl {
             PointOrHsl::Point(point) => self
@@ -314,6 +427,15 @@ impl Poline {
         self.update_anchor_pairs();
     }
 
+    pub fn path_mode(&self) -> PathMode {
+        self.path_mode
+    }
+
+    pub fn set_path_mode(&mut self, path_mode: PathMode) {
+        self.path_mode = path_mode;
+        self.update_anchor_pairs();
+    }
+
     pub fn inverted(&self) -> bool {
         self.inverted
     }
@@ -360,4 +482,198 @@ impl Poline {
         self.anchor_points.iter_mut().for_each(|p| p.shift_hue(val));
         self.update_anchor_pairs()
     }
+
+    pub fn render_strip(&self, width: usize, height: usize) -> Vec<u8> {
+        let colors: Vec<Hsl> = self.colors().collect();
+
+        let row: Vec<(u8, u8, u8)> = (0..width)
+            .map(|x| {
+                if colors.is_empty() {
+                    return (0, 0, 0);
+                }
+
+                let t = if width > 1 {
+                    x as f64 / (width - 1) as f64
+                } else {
+                    0f64
+                };
+                let scaled = t * (colors.len() - 1) as f64;
+                let lower = scaled.floor() as usize;
+                let upper = (lower + 1).min(colors.len() - 1);
+                let frac = scaled - lower as f64;
+
+                let a = colors[lower];
+                let b = colors[upper];
+
+                Hsl {
+                    h: a.h + (b.h - a.h) * frac,
+                    s: a.s + (b.s - a.s) * frac,
+                    l: a.l + (b.l - a.l) * frac,
+                }
+                .to_rgb()
+            })
+            .collect();
+
+        let mut buf = Vec::with_capacity(width * height * 3);
+        for _ in 0..height {
+            for (r, g, b) in &row {
+                buf.push(*r);
+                buf.push(*g);
+                buf.push(*b);
+            }
+        }
+
+        buf
+    }
+
+    pub fn to_ppm(&self, width: usize, height: usize) -> String {
+        let buf = self.render_strip(width, height);
+        let mut out = format!("P3\n{width} {height}\n255\n");
+
+        for pixel in buf.chunks(3) {
+            out.push_str(&format!("{} {} {}\n", pixel[0], pixel[1], pixel[2]));
+        }
+
+        out
+    }
+
+    pub fn apply_transform(&mut self, t: &Transform) {
+        for anchor in self.anchor_points.iter_mut() {
+            let new_point = anchor.point.apply_transform(t);
+            anchor.set_postion(new_point);
+        }
+        self.update_anchor_pairs();
+    }
+
+    pub fn reflect_anchors(&mut self, axis: HarmonyAxis) {
+        for anchor in self.anchor_points.iter_mut() {
+            let reflected = reflect_point(anchor.point, &axis);
+            anchor.set_postion(reflected);
+        }
+        self.update_anchor_pairs();
+    }
+
+    pub fn append_reflected_anchors(&mut self, axis: HarmonyAxis) {
+        let reflected: Vec<ColorPoint> = self
+            .anchor_points
+            .iter()
+            .map(|anchor| {
+                let point = reflect_point(anchor.point, &axis);
+                ColorPoint::from((point, self.inverted))
+            })
+            .collect();
+
+        self.anchor_points.extend(reflected);
+        self.update_anchor_pairs();
+    }
+
+    pub fn split_complementary(&mut self, spread_degrees: f64) {
+        let spread = spread_degrees / (180f64 / PI);
+
+        let complements: Vec<Point3> = self
+            .anchor_points
+            .iter()
+            .map(|anchor| reflect_point(anchor.point, &HarmonyAxis::Complementary))
+            .collect();
+
+        let rotate_pos = Transform::rotate_z(spread);
+        let rotate_neg = Transform::rotate_z(-spread);
+
+        let split: Vec<ColorPoint> = complements
+            .into_iter()
+            .flat_map(|p| {
+                [
+                    ColorPoint::from((p.apply_transform(&rotate_pos), self.inverted)),
+                    ColorPoint::from((p.apply_transform(&rotate_neg), self.inverted)),
+                ]
+            })
+            .collect();
+
+        self.anchor_points.extend(split);
+        self.update_anchor_pairs();
+    }
+
+    pub fn triadic(&mut self) {
+        let rotate_pos = Transform::rotate_z(120f64 / (180f64 / PI));
+        let rotate_neg = Transform::rotate_z(-120f64 / (180f64 / PI));
+
+        let triad: Vec<ColorPoint> = self
+            .anchor_points
+            .iter()
+            .flat_map(|anchor| {
+                [
+                    ColorPoint::from((anchor.point.apply_transform(&rotate_pos), self.inverted)),
+                    ColorPoint::from((anchor.point.apply_transform(&rotate_neg), self.inverted)),
+                ]
+            })
+            .collect();
+
+        self.anchor_points.extend(triad);
+        self.update_anchor_pairs();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complementary_reflection_is_point_symmetric_through_center() {
+        let point = Point3::new(0.9, 0.5, 0.3);
+        let reflected = reflect_point(point, &HarmonyAxis::Complementary);
+        let (rx, ry, rz) = reflected.components();
+        let (px, py, pz) = point.components();
+
+        assert!((rx - (1.0 - px)).abs() < 1e-9);
+        assert!((ry - (1.0 - py)).abs() < 1e-9);
+        assert!((rz - pz).abs() < 1e-9);
+    }
+
+    #[test]
+    fn triadic_appends_two_additional_anchors_per_original() {
+        let mut p = Poline::builder()
+            .anchor_points(vec![
+                Hsl {
+                    h: 0.0,
+                    s: 0.5,
+                    l: 0.5,
+                },
+                Hsl {
+                    h: 200.0,
+                    s: 0.5,
+                    l: 0.5,
+                },
+            ])
+            .build()
+            .unwrap();
+
+        let before = p.anchor_points().len();
+        p.triadic();
+
+        assert_eq!(p.anchor_points().len(), before * 3);
+    }
+
+    #[test]
+    fn split_complementary_takes_degrees() {
+        let mut p = Poline::builder()
+            .anchor_points(vec![
+                Hsl {
+                    h: 0.0,
+                    s: 0.5,
+                    l: 0.5,
+                },
+                Hsl {
+                    h: 200.0,
+                    s: 0.5,
+                    l: 0.5,
+                },
+            ])
+            .build()
+            .unwrap();
+
+        let before = p.anchor_points().len();
+        p.split_complementary(30.0); // degrees, matching HarmonyAxis::Angle and shift_hue
+
+        assert_eq!(p.anchor_points().len(), before * 3);
+    }
 }
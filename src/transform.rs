@@ -0,0 +1,106 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    m: [[f64; 4]; 4],
+}
+
+impl Transform {
+    fn identity() -> Self {
+        Self {
+            m: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    fn multiply(a: &[[f64; 4]; 4], b: &[[f64; 4]; 4]) -> [[f64; 4]; 4] {
+        let mut out = [[0.0; 4]; 4];
+        for (i, row) in out.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..4).map(|k| a[i][k] * b[k][j]).sum();
+            }
+        }
+        out
+    }
+
+    pub fn translate(tx: f64, ty: f64, tz: f64) -> Self {
+        let mut out = Self::identity();
+        out.m[0][3] = tx;
+        out.m[1][3] = ty;
+        out.m[2][3] = tz;
+        out
+    }
+
+    pub fn scale(sx: f64, sy: f64, sz: f64) -> Self {
+        let mut out = Self::identity();
+        out.m[0][0] = sx;
+        out.m[1][1] = sy;
+        out.m[2][2] = sz;
+        out
+    }
+
+    pub fn rotate_z(radians: f64) -> Self {
+        let (s, c) = radians.sin_cos();
+        let mut rotation = Self::identity();
+        rotation.m[0][0] = c;
+        rotation.m[0][1] = -s;
+        rotation.m[1][0] = s;
+        rotation.m[1][1] = c;
+
+        Self::translate(-0.5, -0.5, 0.0)
+            .then(&rotation)
+            .then(&Self::translate(0.5, 0.5, 0.0))
+    }
+
+    pub fn then(&self, other: &Transform) -> Transform {
+        Transform {
+            m: Self::multiply(&other.m, &self.m),
+        }
+    }
+
+    pub(crate) fn apply_point(&self, x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+        let v = [x, y, z, 1.0];
+        let mut out = [0.0; 4];
+        for (i, cell) in out.iter_mut().enumerate() {
+            *cell = (0..4).map(|k| self.m[i][k] * v[k]).sum();
+        }
+        (out[0], out[1], out[2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: (f64, f64, f64), b: (f64, f64, f64)) {
+        assert!((a.0 - b.0).abs() < 1e-9, "{:?} != {:?}", a, b);
+        assert!((a.1 - b.1).abs() < 1e-9, "{:?} != {:?}", a, b);
+        assert!((a.2 - b.2).abs() < 1e-9, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn translate_moves_point() {
+        let t = Transform::translate(0.1, 0.2, 0.3);
+        assert_close(t.apply_point(0.5, 0.5, 0.5), (0.6, 0.7, 0.8));
+    }
+
+    #[test]
+    fn scale_scales_point() {
+        let t = Transform::scale(2.0, 0.5, 1.0);
+        assert_close(t.apply_point(1.0, 1.0, 1.0), (2.0, 0.5, 1.0));
+    }
+
+    #[test]
+    fn rotate_z_quarter_turn_about_center() {
+        let t = Transform::rotate_z(std::f64::consts::FRAC_PI_2);
+        assert_close(t.apply_point(1.0, 0.5, 0.5), (0.5, 1.0, 0.5));
+    }
+
+    #[test]
+    fn then_applies_self_before_other() {
+        let combined = Transform::translate(1.0, 0.0, 0.0).then(&Transform::scale(2.0, 1.0, 1.0));
+        assert_close(combined.apply_point(0.0, 0.0, 0.0), (2.0, 0.0, 0.0));
+    }
+}